@@ -0,0 +1,301 @@
+#![allow(non_camel_case_types)]
+
+use std::os::raw::{c_char, c_double, c_int, c_uint};
+
+#[repr(C)]
+pub struct PopplerDocument(());
+
+#[repr(C)]
+pub struct PopplerPage(());
+
+/// A rectangle in PDF user-space coordinates, as returned by poppler's C API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PopplerRectangle {
+    pub x1: c_double,
+    pub y1: c_double,
+    pub x2: c_double,
+    pub y2: c_double,
+}
+
+/// The discriminant poppler stores as the first field of every `PopplerAction` union member.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopplerActionType {
+    Unknown = 0,
+    None = 1,
+    GotoDest = 2,
+    GotoRemote = 3,
+    Launch = 4,
+    Uri = 5,
+    Named = 6,
+    Movie = 7,
+    Rendition = 8,
+    OcgState = 9,
+    Javascript = 10,
+}
+
+/// The discriminant poppler stores as the first field of `PopplerDest`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopplerDestType {
+    Unknown = 0,
+    Xyz = 1,
+    Fit = 2,
+    Fith = 3,
+    Fitv = 4,
+    Fitr = 5,
+    Fitb = 6,
+    Fitbh = 7,
+    Fitbv = 8,
+    Named = 9,
+}
+
+/// Opaque `PopplerAction` union, accessed through the `PopplerActionHeader`,
+/// `PopplerActionGotoDest`, `PopplerActionUri` and `PopplerActionLaunch` layouts below.
+#[repr(C)]
+pub struct PopplerAction(());
+
+/// The fields common to every `PopplerAction` union member.
+#[repr(C)]
+pub struct PopplerActionHeader {
+    pub type_: PopplerActionType,
+    pub title: *mut c_char,
+}
+
+#[repr(C)]
+pub struct PopplerActionGotoDest {
+    pub type_: PopplerActionType,
+    pub title: *mut c_char,
+    pub dest: *mut PopplerDest,
+}
+
+#[repr(C)]
+pub struct PopplerActionUri {
+    pub type_: PopplerActionType,
+    pub title: *mut c_char,
+    pub uri: *mut c_char,
+}
+
+#[repr(C)]
+pub struct PopplerActionLaunch {
+    pub type_: PopplerActionType,
+    pub title: *mut c_char,
+    pub file_name: *mut c_char,
+    pub params: *mut c_char,
+}
+
+#[repr(C)]
+pub struct PopplerDest {
+    pub type_: PopplerDestType,
+    pub page_num: c_int,
+    pub left: c_double,
+    pub bottom: c_double,
+    pub right: c_double,
+    pub top: c_double,
+    pub zoom: c_double,
+    pub named_dest: *mut c_char,
+    pub change_left: glib_sys::gboolean,
+    pub change_top: glib_sys::gboolean,
+    pub change_zoom: glib_sys::gboolean,
+}
+
+#[repr(C)]
+pub struct PopplerLinkMapping {
+    pub area: PopplerRectangle,
+    pub action: *mut PopplerAction,
+}
+
+/// Opaque cursor into a document's outline (table of contents), one per tree level.
+#[repr(C)]
+pub struct PopplerIndexIter(());
+
+/// Opaque `PopplerFormField` GObject.
+#[repr(C)]
+pub struct PopplerFormField(());
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopplerFormFieldType {
+    Unknown = 0,
+    Button = 1,
+    Text = 2,
+    Choice = 3,
+    Signature = 4,
+}
+
+#[repr(C)]
+pub struct PopplerFormFieldMapping {
+    pub area: PopplerRectangle,
+    pub field: *mut PopplerFormField,
+}
+
+/// The granularity poppler expands a selection rectangle to when resolving it to text/boxes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopplerSelectionStyle {
+    Glyph = 0,
+    Word = 1,
+    Line = 2,
+}
+
+/// An RGB color, as poppler represents it (no alpha), used by `poppler_page_render_selection`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PopplerColor {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+extern "C" {
+    pub fn poppler_document_new_from_file(
+        uri: *const c_char,
+        password: *const c_char,
+        error: *mut *mut glib_sys::GError,
+    ) -> *mut PopplerDocument;
+
+    pub fn poppler_document_new_from_data(
+        data: *mut c_char,
+        length: c_int,
+        password: *const c_char,
+        error: *mut *mut glib_sys::GError,
+    ) -> *mut PopplerDocument;
+
+    pub fn poppler_document_get_title(doc: *mut PopplerDocument) -> *mut c_char;
+    pub fn poppler_document_get_metadata(doc: *mut PopplerDocument) -> *mut c_char;
+    pub fn poppler_document_get_pdf_version_string(doc: *mut PopplerDocument) -> *mut c_char;
+    pub fn poppler_document_get_permissions(doc: *mut PopplerDocument) -> c_int;
+    pub fn poppler_document_get_n_pages(doc: *mut PopplerDocument) -> c_int;
+    pub fn poppler_document_get_page(doc: *mut PopplerDocument, index: c_int) -> *mut PopplerPage;
+
+    pub fn poppler_page_get_size(
+        page: *mut PopplerPage,
+        width: *mut c_double,
+        height: *mut c_double,
+    );
+    pub fn poppler_page_get_text(page: *mut PopplerPage) -> *mut c_char;
+
+    /// Returns the text enclosed by `area`. The result must be freed with `g_free`.
+    pub fn poppler_page_get_text_in_area(
+        page: *mut PopplerPage,
+        area: *mut PopplerRectangle,
+    ) -> *mut c_char;
+
+    /// Fills `rectangles` with a freshly `g_malloc`'d array of one [`PopplerRectangle`] per
+    /// Unicode character of [`poppler_page_get_text`], in the same order. The caller must
+    /// free the array with `g_free`.
+    pub fn poppler_page_get_text_layout(
+        page: *mut PopplerPage,
+        rectangles: *mut *mut PopplerRectangle,
+        n_rectangles: *mut c_uint,
+    ) -> glib_sys::gboolean;
+
+    #[cfg(feature = "render")]
+    pub fn poppler_page_render(page: *mut PopplerPage, cairo: *mut cairo_sys::cairo_t);
+    #[cfg(feature = "render")]
+    pub fn poppler_page_render_for_printing(page: *mut PopplerPage, cairo: *mut cairo_sys::cairo_t);
+
+    /// Returns a `GList` of `PopplerLinkMapping*`, freed with [`poppler_page_free_link_mapping`].
+    pub fn poppler_page_get_link_mapping(page: *mut PopplerPage) -> *mut glib_sys::GList;
+    pub fn poppler_page_free_link_mapping(list: *mut glib_sys::GList);
+
+    /// Resolves a named destination against the document's name tree. Returns `NULL` if there is
+    /// no such destination; the result must be freed with [`poppler_dest_free`].
+    pub fn poppler_document_find_dest(
+        doc: *mut PopplerDocument,
+        named_dest: *const c_char,
+    ) -> *mut PopplerDest;
+    pub fn poppler_dest_free(dest: *mut PopplerDest);
+
+    /// Returns an iterator over the top-level outline entries, or `NULL` if the document has no
+    /// outline. Every iterator, including ones returned by [`poppler_index_iter_get_child`], must
+    /// be freed with [`poppler_index_iter_free`].
+    pub fn poppler_index_iter_new(doc: *mut PopplerDocument) -> *mut PopplerIndexIter;
+    /// Returns the action (title + destination) of the entry `iter` currently points to. The
+    /// caller owns the result and must free it with `poppler_action_free`.
+    pub fn poppler_index_iter_get_action(iter: *mut PopplerIndexIter) -> *mut PopplerAction;
+    /// Returns a new iterator over the children of the entry `iter` currently points to, or
+    /// `NULL` if it has none.
+    pub fn poppler_index_iter_get_child(iter: *mut PopplerIndexIter) -> *mut PopplerIndexIter;
+    /// Advances `iter` to the next sibling in place; returns `FALSE` once there is none.
+    pub fn poppler_index_iter_next(iter: *mut PopplerIndexIter) -> glib_sys::gboolean;
+    pub fn poppler_index_iter_free(iter: *mut PopplerIndexIter);
+    pub fn poppler_action_free(action: *mut PopplerAction);
+
+    /// Returns a `GList` of `PopplerFormFieldMapping*`. Each mapping's `field` is unreffed by
+    /// [`poppler_page_free_form_field_mapping`], so callers keeping a field alive past that call
+    /// must take their own reference with `g_object_ref` first.
+    pub fn poppler_page_get_form_field_mapping(page: *mut PopplerPage) -> *mut glib_sys::GList;
+    pub fn poppler_page_free_form_field_mapping(list: *mut glib_sys::GList);
+
+    pub fn poppler_document_get_form_field(
+        doc: *mut PopplerDocument,
+        id: c_int,
+    ) -> *mut PopplerFormField;
+
+    pub fn poppler_form_field_get_field_type(field: *mut PopplerFormField) -> PopplerFormFieldType;
+    /// Returns the id that can be passed to `poppler_document_get_form_field` to look this field
+    /// up again.
+    pub fn poppler_form_field_get_id(field: *mut PopplerFormField) -> c_int;
+
+    pub fn poppler_form_field_text_get_text(field: *mut PopplerFormField) -> *mut c_char;
+    pub fn poppler_form_field_text_set_text(field: *mut PopplerFormField, text: *const c_char);
+
+    pub fn poppler_form_field_button_get_state(field: *mut PopplerFormField) -> glib_sys::gboolean;
+    pub fn poppler_form_field_button_set_state(
+        field: *mut PopplerFormField,
+        state: glib_sys::gboolean,
+    );
+
+    pub fn poppler_form_field_choice_get_n_items(field: *mut PopplerFormField) -> c_int;
+    pub fn poppler_form_field_choice_get_item(
+        field: *mut PopplerFormField,
+        index: c_int,
+    ) -> *mut c_char;
+    pub fn poppler_form_field_choice_is_item_selected(
+        field: *mut PopplerFormField,
+        index: c_int,
+    ) -> glib_sys::gboolean;
+    pub fn poppler_form_field_choice_select_item(field: *mut PopplerFormField, index: c_int);
+
+    /// Returns the text enclosed by `selection`, expanding it to whole glyphs/words/lines per
+    /// `style`. The result must be freed with `g_free`.
+    pub fn poppler_page_get_selected_text(
+        page: *mut PopplerPage,
+        style: PopplerSelectionStyle,
+        selection: *mut PopplerRectangle,
+    ) -> *mut c_char;
+
+    #[cfg(feature = "render")]
+    pub fn poppler_page_render_selection(
+        page: *mut PopplerPage,
+        cairo: *mut cairo_sys::cairo_t,
+        selection: *mut PopplerRectangle,
+        old_selection: *mut PopplerRectangle,
+        style: PopplerSelectionStyle,
+        glyph_color: *mut PopplerColor,
+        background_color: *mut PopplerColor,
+    );
+
+    /// Returns the region covered by `selection`, as a `cairo_region_t*` freed with
+    /// `cairo_region_destroy`.
+    #[cfg(feature = "render")]
+    pub fn poppler_page_get_selection_region(
+        page: *mut PopplerPage,
+        scale: c_double,
+        style: PopplerSelectionStyle,
+        selection: *mut PopplerRectangle,
+    ) -> *mut cairo_sys::cairo_region_t;
+
+    pub fn poppler_document_save(
+        doc: *mut PopplerDocument,
+        uri: *const c_char,
+        error: *mut *mut glib_sys::GError,
+    ) -> glib_sys::gboolean;
+    pub fn poppler_document_save_a_copy(
+        doc: *mut PopplerDocument,
+        uri: *const c_char,
+        error: *mut *mut glib_sys::GError,
+    ) -> glib_sys::gboolean;
+}
@@ -1,7 +1,8 @@
 use std::ffi::CStr;
 use std::ffi::CString;
-use std::os::raw::{c_char, c_double, c_int};
+use std::os::raw::{c_char, c_double, c_int, c_uint};
 use std::path;
+use std::ptr;
 
 /// Re-exports `cairo` to provide types required for rendering.
 #[cfg(feature = "render")]
@@ -18,6 +19,399 @@ pub struct PopplerDocument(*mut ffi::PopplerDocument);
 #[derive(Debug)]
 pub struct PopplerPage(*mut ffi::PopplerPage);
 
+/// A rectangular region of a page, in PDF user-space coordinates (points, origin at the
+/// top-left of the page).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+impl From<ffi::PopplerRectangle> for Rectangle {
+    fn from(r: ffi::PopplerRectangle) -> Self {
+        Rectangle {
+            x1: r.x1,
+            y1: r.y1,
+            x2: r.x2,
+            y2: r.y2,
+        }
+    }
+}
+
+impl Rectangle {
+    fn to_raw(self) -> ffi::PopplerRectangle {
+        ffi::PopplerRectangle {
+            x1: self.x1,
+            y1: self.y1,
+            x2: self.x2,
+            y2: self.y2,
+        }
+    }
+}
+
+/// The granularity a selection rectangle is expanded to: individual glyphs, whole words, or
+/// whole lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStyle {
+    Glyph,
+    Word,
+    Line,
+}
+
+impl From<SelectionStyle> for ffi::PopplerSelectionStyle {
+    fn from(style: SelectionStyle) -> Self {
+        match style {
+            SelectionStyle::Glyph => ffi::PopplerSelectionStyle::Glyph,
+            SelectionStyle::Word => ffi::PopplerSelectionStyle::Word,
+            SelectionStyle::Line => ffi::PopplerSelectionStyle::Line,
+        }
+    }
+}
+
+/// An RGB color, components in `0..=65535` as poppler's `PopplerColor` represents them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+impl From<Color> for ffi::PopplerColor {
+    fn from(c: Color) -> Self {
+        ffi::PopplerColor {
+            red: c.red,
+            green: c.green,
+            blue: c.blue,
+        }
+    }
+}
+
+/// A destination that a [`LinkAction::GotoDestination`] jumps to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Destination {
+    /// A destination already resolved to a page number, counted from 1 as poppler reports it.
+    PageNumber(i32),
+    /// A named destination that must be resolved with [`PopplerDocument::find_dest`].
+    Named(String),
+}
+
+impl Destination {
+    fn from_raw(dest: &ffi::PopplerDest) -> Self {
+        if dest.type_ == ffi::PopplerDestType::Named {
+            let name = unsafe { CStr::from_ptr(dest.named_dest) }
+                .to_string_lossy()
+                .into_owned();
+            Destination::Named(name)
+        } else {
+            Destination::PageNumber(dest.page_num)
+        }
+    }
+}
+
+/// The action triggered by activating a [`LinkMapping`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkAction {
+    /// Jump to a destination within the same document.
+    GotoDestination(Destination),
+    /// Open an external URI.
+    Uri(String),
+    /// Launch an external file, with optional parameters.
+    Launch {
+        file_name: String,
+        params: Option<String>,
+    },
+    /// An action type this crate does not yet decode.
+    Unsupported,
+}
+
+/// A clickable region of a page and the action it triggers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkMapping {
+    pub area: Rectangle,
+    pub action: LinkAction,
+}
+
+/// Reads a `PopplerAction*`, whose layout depends on its `type_` tag, into an owned [`LinkAction`].
+fn link_action_from_raw(action: *const ffi::PopplerAction) -> LinkAction {
+    unsafe {
+        let header = &*(action as *const ffi::PopplerActionHeader);
+        match header.type_ {
+            ffi::PopplerActionType::GotoDest => {
+                let goto_dest = &*(action as *const ffi::PopplerActionGotoDest);
+                if goto_dest.dest.is_null() {
+                    LinkAction::Unsupported
+                } else {
+                    LinkAction::GotoDestination(Destination::from_raw(&*goto_dest.dest))
+                }
+            }
+            ffi::PopplerActionType::Uri => {
+                let uri = &*(action as *const ffi::PopplerActionUri);
+                LinkAction::Uri(CStr::from_ptr(uri.uri).to_string_lossy().into_owned())
+            }
+            ffi::PopplerActionType::Launch => {
+                let launch = &*(action as *const ffi::PopplerActionLaunch);
+                LinkAction::Launch {
+                    file_name: CStr::from_ptr(launch.file_name).to_string_lossy().into_owned(),
+                    params: if launch.params.is_null() {
+                        None
+                    } else {
+                        Some(CStr::from_ptr(launch.params).to_string_lossy().into_owned())
+                    },
+                }
+            }
+            _ => LinkAction::Unsupported,
+        }
+    }
+}
+
+/// A single entry of a document's outline (table of contents), as returned by
+/// [`PopplerDocument::outline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineItem {
+    pub title: String,
+    pub dest_page: Option<usize>,
+    pub children: Vec<OutlineItem>,
+}
+
+/// Resolves a [`Destination`] (as decoded from an outline entry's action) to a page number.
+fn resolve_dest_page(doc: *mut ffi::PopplerDocument, destination: &Destination) -> Option<usize> {
+    match destination {
+        Destination::PageNumber(n) => (*n >= 0).then(|| *n as usize),
+        Destination::Named(name) => {
+            let name = CString::new(name.as_str()).ok()?;
+            unsafe {
+                let dest = ffi::poppler_document_find_dest(doc, name.as_ptr());
+                if dest.is_null() {
+                    return None;
+                }
+                let page_num = (*dest).page_num;
+                ffi::poppler_dest_free(dest);
+                (page_num >= 0).then(|| page_num as usize)
+            }
+        }
+    }
+}
+
+/// Reads one level of the outline tree, starting at `iter` and walking its siblings.
+fn read_outline_level(
+    doc: *mut ffi::PopplerDocument,
+    iter: *mut ffi::PopplerIndexIter,
+) -> Vec<OutlineItem> {
+    let mut items = Vec::new();
+
+    loop {
+        unsafe {
+            let action = ffi::poppler_index_iter_get_action(iter);
+            if !action.is_null() {
+                let header = &*(action as *const ffi::PopplerActionHeader);
+                let title = if header.title.is_null() {
+                    String::new()
+                } else {
+                    CStr::from_ptr(header.title).to_string_lossy().into_owned()
+                };
+
+                let dest_page = match link_action_from_raw(action) {
+                    LinkAction::GotoDestination(destination) => {
+                        resolve_dest_page(doc, &destination)
+                    }
+                    _ => None,
+                };
+
+                ffi::poppler_action_free(action);
+
+                let child_iter = ffi::poppler_index_iter_get_child(iter);
+                let children = if child_iter.is_null() {
+                    Vec::new()
+                } else {
+                    let children = read_outline_level(doc, child_iter);
+                    ffi::poppler_index_iter_free(child_iter);
+                    children
+                };
+
+                items.push(OutlineItem {
+                    title,
+                    dest_page,
+                    children,
+                });
+            }
+
+            if ffi::poppler_index_iter_next(iter) == 0 {
+                break;
+            }
+        }
+    }
+
+    items
+}
+
+/// The kind of data a [`FormField`] holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    Unknown,
+    /// A checkbox or radio button.
+    Button,
+    Text,
+    Choice,
+    Signature,
+}
+
+impl From<ffi::PopplerFormFieldType> for FieldType {
+    fn from(t: ffi::PopplerFormFieldType) -> Self {
+        match t {
+            ffi::PopplerFormFieldType::Button => FieldType::Button,
+            ffi::PopplerFormFieldType::Text => FieldType::Text,
+            ffi::PopplerFormFieldType::Choice => FieldType::Choice,
+            ffi::PopplerFormFieldType::Signature => FieldType::Signature,
+            ffi::PopplerFormFieldType::Unknown => FieldType::Unknown,
+        }
+    }
+}
+
+/// A single AcroForm field of a [`PopplerDocument`].
+#[derive(Debug)]
+pub struct FormField(*mut ffi::PopplerFormField);
+
+impl FormField {
+    /// Returns the id that can be passed to [`PopplerDocument::get_form_field`] to look this
+    /// field up again.
+    pub fn id(&self) -> i32 {
+        unsafe { ffi::poppler_form_field_get_id(self.0) }
+    }
+
+    /// Returns the kind of data this field holds.
+    pub fn field_type(&self) -> FieldType {
+        FieldType::from(unsafe { ffi::poppler_form_field_get_field_type(self.0) })
+    }
+
+    /// Returns the contents of a [`FieldType::Text`] field.
+    pub fn text(&self) -> Option<String> {
+        unsafe {
+            let ptr = ffi::poppler_form_field_text_get_text(self.0);
+            if ptr.is_null() {
+                None
+            } else {
+                let text = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+                glib_sys::g_free(ptr as glib_sys::gpointer);
+                Some(text)
+            }
+        }
+    }
+
+    /// Sets the contents of a [`FieldType::Text`] field.
+    pub fn set_text(&self, text: &str) {
+        if let Ok(text) = CString::new(text) {
+            unsafe { ffi::poppler_form_field_text_set_text(self.0, text.as_ptr()) }
+        }
+    }
+
+    /// Returns whether a [`FieldType::Button`] field (checkbox/radio button) is checked.
+    pub fn button_state(&self) -> bool {
+        unsafe { ffi::poppler_form_field_button_get_state(self.0) != 0 }
+    }
+
+    /// Checks or unchecks a [`FieldType::Button`] field.
+    pub fn set_button_state(&self, state: bool) {
+        unsafe { ffi::poppler_form_field_button_set_state(self.0, state as glib_sys::gboolean) }
+    }
+
+    /// Returns the number of selectable items of a [`FieldType::Choice`] field.
+    pub fn n_choices(&self) -> i32 {
+        unsafe { ffi::poppler_form_field_choice_get_n_items(self.0) }
+    }
+
+    /// Returns the label of the `index`th item of a [`FieldType::Choice`] field.
+    pub fn choice_item(&self, index: i32) -> Option<String> {
+        unsafe {
+            let ptr = ffi::poppler_form_field_choice_get_item(self.0, index);
+            if ptr.is_null() {
+                None
+            } else {
+                let item = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+                glib_sys::g_free(ptr as glib_sys::gpointer);
+                Some(item)
+            }
+        }
+    }
+
+    /// Returns whether the `index`th item of a [`FieldType::Choice`] field is selected.
+    pub fn is_choice_item_selected(&self, index: i32) -> bool {
+        unsafe { ffi::poppler_form_field_choice_is_item_selected(self.0, index) != 0 }
+    }
+
+    /// Selects the `index`th item of a [`FieldType::Choice`] field.
+    pub fn select_choice_item(&self, index: i32) {
+        unsafe { ffi::poppler_form_field_choice_select_item(self.0, index) }
+    }
+}
+
+impl Drop for FormField {
+    fn drop(&mut self) {
+        unsafe {
+            gobject_sys::g_object_unref(self.0 as *mut gobject_sys::GObject);
+        }
+    }
+}
+
+/// A form field together with the page area it occupies.
+#[derive(Debug)]
+pub struct FormFieldMapping {
+    pub area: Rectangle,
+    pub field: FormField,
+}
+
+/// Vector output formats supported by [`PopplerDocument::export_to`].
+#[cfg(feature = "render")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Pdf,
+    Ps,
+    Svg,
+}
+
+/// Falls back to A4 when a page reports a non-positive size, matching poppler's own `pdftops`.
+#[cfg(feature = "render")]
+const EXPORT_FALLBACK_SIZE: (f64, f64) = (595.0, 842.0);
+
+#[cfg(feature = "render")]
+enum ExportSurface {
+    Pdf(cairo::PdfSurface),
+    Ps(cairo::PsSurface),
+    Svg(cairo::SvgSurface),
+}
+
+#[cfg(feature = "render")]
+impl ExportSurface {
+    fn as_surface(&self) -> &cairo::Surface {
+        match self {
+            ExportSurface::Pdf(s) => s,
+            ExportSurface::Ps(s) => s,
+            ExportSurface::Svg(s) => s,
+        }
+    }
+
+    /// Resizes the surface ahead of the next page. Only PDF and PS surfaces support this;
+    /// cairo has no equivalent for SVG, so pages there keep the first page's geometry.
+    fn resize(&self, width: f64, height: f64) -> Result<(), cairo::Error> {
+        match self {
+            ExportSurface::Pdf(s) => s.set_size(width, height),
+            ExportSurface::Ps(s) => Ok(s.set_size(width, height)),
+            ExportSurface::Svg(_) => Ok(()),
+        }
+    }
+
+    /// Emits a `%%DocumentMedia`/page bounding box pair for PostScript output, as `pdftops` does.
+    fn emit_ps_page_media(&self, width: f64, height: f64) {
+        if let ExportSurface::Ps(s) = self {
+            let (width, height) = (width.ceil() as i64, height.ceil() as i64);
+            s.dsc_comment(&format!("%%DocumentMedia: plain {} {} 0 () ()", width, height));
+            s.begin_page_setup();
+            s.dsc_comment(&format!("%%PageBBox: 0 0 {} {}", width, height));
+        }
+    }
+}
+
 impl PopplerDocument {
     /// Creates a new Poppler document.
     pub fn new_from_file<P: AsRef<path::Path>>(
@@ -150,6 +544,112 @@ impl PopplerDocument {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Resolves a named destination (as produced by [`Destination::Named`]) to a page number.
+    pub fn find_dest(&self, named_dest: &str) -> Option<i32> {
+        let named_dest = CString::new(named_dest).ok()?;
+
+        unsafe {
+            let dest = ffi::poppler_document_find_dest(self.0, named_dest.as_ptr());
+            if dest.is_null() {
+                return None;
+            }
+
+            let page_num = (*dest).page_num;
+            ffi::poppler_dest_free(dest);
+            Some(page_num)
+        }
+    }
+
+    /// Returns the document's outline (table of contents / bookmarks) as a tree, or an empty
+    /// `Vec` if the document has none.
+    pub fn outline(&self) -> Vec<OutlineItem> {
+        unsafe {
+            let iter = ffi::poppler_index_iter_new(self.0);
+            if iter.is_null() {
+                return Vec::new();
+            }
+
+            let items = read_outline_level(self.0, iter);
+            ffi::poppler_index_iter_free(iter);
+            items
+        }
+    }
+
+    /// Looks up an AcroForm field by its id, as found via [`PopplerPage::get_form_field_mapping`].
+    pub fn get_form_field(&self, id: i32) -> Option<FormField> {
+        match unsafe { ffi::poppler_document_get_form_field(self.0, id) } {
+            ptr if ptr.is_null() => None,
+            ptr => Some(FormField(ptr)),
+        }
+    }
+
+    /// Renders every page into a single vector file at `path`, one surface page per document
+    /// page. Each page is resized to its own geometry before rendering, so documents with
+    /// heterogeneous page sizes (a technique borrowed from librsvg's mixed-size export) still
+    /// come out correctly for PDF and PS targets.
+    #[cfg(feature = "render")]
+    pub fn export_to<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        format: ExportFormat,
+    ) -> Result<(), cairo::Error> {
+        let path = path.as_ref();
+        let (width, height) = self
+            .get_page(0)
+            .map(|page| page.get_size())
+            .filter(|&(w, h)| w > 0.0 && h > 0.0)
+            .unwrap_or(EXPORT_FALLBACK_SIZE);
+
+        let surface = match format {
+            ExportFormat::Pdf => ExportSurface::Pdf(cairo::PdfSurface::new(width, height, path)?),
+            ExportFormat::Ps => ExportSurface::Ps(cairo::PsSurface::new(width, height, path)?),
+            ExportFormat::Svg => ExportSurface::Svg(cairo::SvgSurface::new(width, height, path)?),
+        };
+
+        let ctx = cairo::Context::new(surface.as_surface())?;
+
+        for page in self.pages() {
+            let (width, height) = match page.get_size() {
+                (w, h) if w > 0.0 && h > 0.0 => (w, h),
+                _ => EXPORT_FALLBACK_SIZE,
+            };
+
+            surface.resize(width, height)?;
+            surface.emit_ps_page_media(width, height);
+
+            ctx.save()?;
+            match format {
+                ExportFormat::Svg => page.render(&ctx),
+                ExportFormat::Pdf | ExportFormat::Ps => page.render_for_printing(&ctx),
+            }
+            ctx.restore()?;
+            ctx.show_page()?;
+        }
+
+        surface.as_surface().finish();
+
+        Ok(())
+    }
+
+    /// Saves the document, including any edited forms/annotations, to `path`.
+    pub fn save<P: AsRef<path::Path>>(&self, path: P) -> Result<(), glib::error::Error> {
+        let uri = util::path_to_glib_url(path)?;
+        util::call_with_gerror(|err_ptr| unsafe {
+            ffi::poppler_document_save(self.0, uri.as_ptr(), err_ptr)
+        })?;
+        Ok(())
+    }
+
+    /// Saves a copy of the document as it was loaded to `path`. Unlike [`save`](Self::save),
+    /// changes made since (such as filled-in form fields) are not written.
+    pub fn save_a_copy<P: AsRef<path::Path>>(&self, path: P) -> Result<(), glib::error::Error> {
+        let uri = util::path_to_glib_url(path)?;
+        util::call_with_gerror(|err_ptr| unsafe {
+            ffi::poppler_document_save_a_copy(self.0, uri.as_ptr(), err_ptr)
+        })?;
+        Ok(())
+    }
 }
 
 impl Drop for PopplerDocument {
@@ -210,6 +710,61 @@ impl PopplerPage {
         unsafe { ffi::poppler_page_render_for_printing(self.0, ctx_raw) }
     }
 
+    /// Renders the page to a tightly-packed buffer of ARGB32 bytes at the given `scale` and
+    /// `rotation` (in degrees), ready to hand to an image library such as the `image` crate
+    /// without touching cairo directly. Returns the buffer along with its pixel dimensions.
+    ///
+    /// The canvas is sized to the rotated page's bounding box (so e.g. a 90 or 270 degree
+    /// rotation swaps the reported width and height), and the page is translated back into
+    /// `[0, pixel_width] x [0, pixel_height]` after rotating.
+    #[cfg(feature = "render")]
+    pub fn render_to_image(&self, scale: f64, rotation: f64) -> (Vec<u8>, u32, u32) {
+        let (width, height) = self.get_size();
+        let scaled_width = width * scale;
+        let scaled_height = height * scale;
+
+        let theta = rotation.to_radians();
+        let (sin, cos) = theta.sin_cos();
+        let corners = [
+            (0.0, 0.0),
+            (scaled_width, 0.0),
+            (scaled_width, scaled_height),
+            (0.0, scaled_height),
+        ]
+        .map(|(x, y)| (x * cos - y * sin, x * sin + y * cos));
+
+        let min_x = corners.iter().fold(f64::INFINITY, |acc, (x, _)| acc.min(*x));
+        let min_y = corners.iter().fold(f64::INFINITY, |acc, (_, y)| acc.min(*y));
+        let max_x = corners.iter().fold(f64::NEG_INFINITY, |acc, (x, _)| acc.max(*x));
+        let max_y = corners.iter().fold(f64::NEG_INFINITY, |acc, (_, y)| acc.max(*y));
+
+        let pixel_width = (max_x - min_x).ceil() as i32;
+        let pixel_height = (max_y - min_y).ceil() as i32;
+
+        let mut surface =
+            cairo::ImageSurface::create(cairo::Format::ARgb32, pixel_width, pixel_height)
+                .expect("failed to create image surface");
+
+        {
+            let ctx = cairo::Context::new(&surface).expect("failed to create cairo context");
+            ctx.translate(-min_x, -min_y);
+            ctx.rotate(theta);
+            ctx.scale(scale, scale);
+            self.render(&ctx);
+        }
+
+        let stride = surface.stride() as usize;
+        let row_bytes = pixel_width as usize * 4;
+        let data = surface.data().expect("failed to map image surface data");
+
+        let mut buffer = Vec::with_capacity(row_bytes * pixel_height as usize);
+        for row in data.chunks(stride).take(pixel_height as usize) {
+            buffer.extend_from_slice(&row[..row_bytes]);
+        }
+
+        (buffer, pixel_width as u32, pixel_height as u32)
+    }
+
     /// Retrieves the text of the page.
     pub fn get_text(&self) -> Option<&str> {
         match unsafe { ffi::poppler_page_get_text(self.0) } {
@@ -217,6 +772,191 @@ impl PopplerPage {
             ptr => unsafe { Some(CStr::from_ptr(ptr).to_str().unwrap_or_default()) },
         }
     }
+
+    /// Retrieves the text enclosed by `area`.
+    pub fn get_text_for_area(&self, area: &Rectangle) -> Option<String> {
+        unsafe {
+            let mut area = area.to_raw();
+            let ptr = ffi::poppler_page_get_text_in_area(self.0, &mut area);
+            if ptr.is_null() {
+                None
+            } else {
+                let text = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+                glib_sys::g_free(ptr as glib_sys::gpointer);
+                Some(text)
+            }
+        }
+    }
+
+    /// Returns the bounding box of each Unicode character of [`get_text`](Self::get_text), in
+    /// the same order, or `None` if the page has no text layout information.
+    pub fn get_text_layout(&self) -> Option<Vec<Rectangle>> {
+        unsafe {
+            let mut rectangles: *mut ffi::PopplerRectangle = ptr::null_mut();
+            let mut n_rectangles: c_uint = 0;
+
+            let has_layout = ffi::poppler_page_get_text_layout(
+                self.0,
+                &mut rectangles as *mut *mut ffi::PopplerRectangle,
+                &mut n_rectangles as *mut c_uint,
+            );
+
+            if has_layout == 0 || rectangles.is_null() {
+                return None;
+            }
+
+            let layout = std::slice::from_raw_parts(rectangles, n_rectangles as usize)
+                .iter()
+                .map(|r| Rectangle::from(*r))
+                .collect();
+
+            glib_sys::g_free(rectangles as glib_sys::gpointer);
+
+            Some(layout)
+        }
+    }
+
+    /// Returns the clickable regions of the page (links and cross-references), which the caller
+    /// can use to build hyperlink overlays or navigation.
+    pub fn get_link_mapping(&self) -> Vec<LinkMapping> {
+        unsafe {
+            let list = ffi::poppler_page_get_link_mapping(self.0);
+            let n = glib_sys::g_list_length(list);
+
+            let mut mappings = Vec::with_capacity(n as usize);
+            for i in 0..n {
+                let node = glib_sys::g_list_nth_data(list, i) as *const ffi::PopplerLinkMapping;
+                mappings.push(LinkMapping {
+                    area: Rectangle::from((*node).area),
+                    action: link_action_from_raw((*node).action),
+                });
+            }
+
+            ffi::poppler_page_free_link_mapping(list);
+
+            mappings
+        }
+    }
+
+    /// Returns the page's AcroForm fields together with the area each occupies.
+    pub fn get_form_field_mapping(&self) -> Vec<FormFieldMapping> {
+        unsafe {
+            let list = ffi::poppler_page_get_form_field_mapping(self.0);
+            let n = glib_sys::g_list_length(list);
+
+            let mut mappings = Vec::with_capacity(n as usize);
+            for i in 0..n {
+                let node =
+                    glib_sys::g_list_nth_data(list, i) as *const ffi::PopplerFormFieldMapping;
+                // `poppler_page_free_form_field_mapping` unrefs every field, so take our own
+                // reference to keep it alive in the returned `FormField`.
+                gobject_sys::g_object_ref((*node).field as *mut gobject_sys::GObject);
+                mappings.push(FormFieldMapping {
+                    area: Rectangle::from((*node).area),
+                    field: FormField((*node).field),
+                });
+            }
+
+            ffi::poppler_page_free_form_field_mapping(list);
+
+            mappings
+        }
+    }
+
+    /// Returns the text enclosed by `selection`, expanded to whole glyphs/words/lines per
+    /// `style`.
+    pub fn get_selected_text(&self, style: SelectionStyle, selection: Rectangle) -> Option<String> {
+        unsafe {
+            let mut selection = selection.to_raw();
+            let ptr =
+                ffi::poppler_page_get_selected_text(self.0, style.into(), &mut selection);
+            if ptr.is_null() {
+                None
+            } else {
+                let text = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+                glib_sys::g_free(ptr as glib_sys::gpointer);
+                Some(text)
+            }
+        }
+    }
+
+    /// Draws `selection` as a highlight box onto `ctx`, clearing away `old_selection`'s
+    /// highlight (if any) in the process.
+    #[cfg(feature = "render")]
+    pub fn render_selection(
+        &self,
+        ctx: &cairo::Context,
+        selection: Rectangle,
+        old_selection: Option<Rectangle>,
+        style: SelectionStyle,
+        glyph_color: Color,
+        background_color: Color,
+    ) {
+        let mut selection = selection.to_raw();
+        let mut old_selection = old_selection.map(Rectangle::to_raw);
+        let old_selection_ptr = old_selection
+            .as_mut()
+            .map_or(ptr::null_mut(), |r| r as *mut ffi::PopplerRectangle);
+        let mut glyph_color = ffi::PopplerColor::from(glyph_color);
+        let mut background_color = ffi::PopplerColor::from(background_color);
+
+        unsafe {
+            ffi::poppler_page_render_selection(
+                self.0,
+                ctx.to_raw_none(),
+                &mut selection,
+                old_selection_ptr,
+                style.into(),
+                &mut glyph_color,
+                &mut background_color,
+            )
+        }
+    }
+
+    /// Returns the boxes covered by `selection`, at `scale`, expanded per `style`.
+    #[cfg(feature = "render")]
+    pub fn get_selection_region(
+        &self,
+        scale: f64,
+        style: SelectionStyle,
+        selection: Rectangle,
+    ) -> Vec<Rectangle> {
+        unsafe {
+            let mut selection = selection.to_raw();
+            let region = ffi::poppler_page_get_selection_region(
+                self.0,
+                scale,
+                style.into(),
+                &mut selection,
+            );
+
+            if region.is_null() {
+                return Vec::new();
+            }
+
+            let n = cairo_sys::cairo_region_num_rectangles(region);
+            let mut rectangles = Vec::with_capacity(n as usize);
+            for i in 0..n {
+                let mut rect = cairo_sys::cairo_rectangle_int_t {
+                    x: 0,
+                    y: 0,
+                    width: 0,
+                    height: 0,
+                };
+                cairo_sys::cairo_region_get_rectangle(region, i, &mut rect);
+                rectangles.push(Rectangle {
+                    x1: rect.x as f64,
+                    y1: rect.y as f64,
+                    x2: (rect.x + rect.width) as f64,
+                    y2: (rect.y + rect.height) as f64,
+                });
+            }
+
+            cairo_sys::cairo_region_destroy(region);
+
+            rectangles
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -297,6 +1037,143 @@ mod tests {
         surface.finish();
     }
 
+    #[test]
+    fn test5_text_layout() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+        let page = doc.get_page(0).unwrap();
+
+        if let Some(layout) = page.get_text_layout() {
+            let text_len = page.get_text().unwrap_or("").chars().count();
+            assert_eq!(layout.len(), text_len);
+        }
+
+        let (w, h) = page.get_size();
+        let area = crate::Rectangle {
+            x1: 0.0,
+            y1: 0.0,
+            x2: w,
+            y2: h,
+        };
+        println!("Text in area: {:?}", page.get_text_for_area(&area));
+    }
+
+    #[test]
+    fn test6_link_mapping() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+        let page = doc.get_page(0).unwrap();
+
+        let links = page.get_link_mapping();
+        println!("Found {} link(s) on page 0", links.len());
+        for link in &links {
+            println!("{:?}", link);
+        }
+    }
+
+    #[test]
+    fn test7_outline() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+
+        let outline = doc.outline();
+        println!("Outline has {} top-level entr(ies)", outline.len());
+        for item in &outline {
+            println!("{:?}", item);
+        }
+    }
+
+    #[test]
+    fn test8_form_fields() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+        let page = doc.get_page(0).unwrap();
+
+        let mappings = page.get_form_field_mapping();
+        println!("Found {} form field(s) on page 0", mappings.len());
+        for mapping in &mappings {
+            println!("{:?}", mapping.field.field_type());
+            let field = doc.get_form_field(mapping.field.id()).unwrap();
+            assert_eq!(field.field_type(), mapping.field.field_type());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "render")]
+    fn test9_export_to() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+
+        doc.export_to("test-export.pdf", crate::ExportFormat::Pdf)
+            .unwrap();
+        doc.export_to("test-export.ps", crate::ExportFormat::Ps)
+            .unwrap();
+        doc.export_to("test-export.svg", crate::ExportFormat::Svg)
+            .unwrap();
+    }
+
+    #[test]
+    fn test10_selection() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+        let page = doc.get_page(0).unwrap();
+        let (w, h) = page.get_size();
+        let selection = crate::Rectangle {
+            x1: 0.0,
+            y1: 0.0,
+            x2: w,
+            y2: h,
+        };
+
+        println!(
+            "Selected text: {:?}",
+            page.get_selected_text(crate::SelectionStyle::Word, selection)
+        );
+
+        #[cfg(feature = "render")]
+        {
+            let region = page.get_selection_region(1.0, crate::SelectionStyle::Word, selection);
+            println!("Selection covers {} rectangle(s)", region.len());
+
+            let surface = ImageSurface::create(Format::ARgb32, w as i32, h as i32).unwrap();
+            let ctx = Context::new(&surface).unwrap();
+            page.render_selection(
+                &ctx,
+                selection,
+                None,
+                crate::SelectionStyle::Word,
+                crate::Color {
+                    red: 0,
+                    green: 0,
+                    blue: 0,
+                },
+                crate::Color {
+                    red: 65535,
+                    green: 65535,
+                    blue: 0,
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test11_save_a_copy() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+        doc.save_a_copy("test-copy.pdf").unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "render")]
+    fn test12_render_to_image() {
+        let doc = PopplerDocument::new_from_file("test.pdf", None).unwrap();
+        let page = doc.get_page(0).unwrap();
+
+        let (data, width, height) = page.render_to_image(1.0, 0.0);
+        assert_eq!(data.len(), width as usize * height as usize * 4);
+
+        let (rotated_data, rotated_width, rotated_height) = page.render_to_image(1.0, 90.0);
+        assert_eq!(
+            rotated_data.len(),
+            rotated_width as usize * rotated_height as usize * 4
+        );
+        assert_eq!(rotated_width, height);
+        assert_eq!(rotated_height, width);
+    }
+
     #[test]
     fn test2_from_file() {
         let path = "test.pdf";
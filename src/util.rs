@@ -0,0 +1,40 @@
+use glib::translate::*;
+use std::ffi::CString;
+use std::path::Path;
+
+/// Converts a filesystem path into a `file://` URL that poppler's C API expects.
+///
+/// The path is made absolute relative to the current directory but not otherwise resolved, so
+/// this also works for destination paths (e.g. for [`PopplerDocument::save`](crate::PopplerDocument::save))
+/// that don't exist yet.
+pub fn path_to_glib_url<P: AsRef<Path>>(path: P) -> Result<CString, glib::error::Error> {
+    let path = path.as_ref();
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map_err(|e| glib::error::Error::new(glib::FileError::Noent, &e.to_string()))?
+            .join(path)
+    };
+
+    CString::new(format!("file://{}", absolute.display())).map_err(|_| {
+        glib::error::Error::new(
+            glib::FileError::Inval,
+            "path contains NUL characters",
+        )
+    })
+}
+
+/// Calls `f` with a `GError**` out-parameter and turns a populated `GError` into an `Err`.
+pub fn call_with_gerror<T>(
+    f: impl FnOnce(*mut *mut glib_sys::GError) -> T,
+) -> Result<T, glib::error::Error> {
+    let mut error: *mut glib_sys::GError = std::ptr::null_mut();
+    let result = f(&mut error);
+
+    if error.is_null() {
+        Ok(result)
+    } else {
+        Err(unsafe { from_glib_full(error) })
+    }
+}